@@ -1,4 +1,5 @@
 use std::borrow::Cow;
+use std::collections::BTreeSet;
 use std::ptr;
 use std::thread;
 
@@ -14,6 +15,9 @@ enum Size {
         items_count: i32,
         height_in_items: i32,
     },
+    AutoWidth {
+        max_height_in_items: i32,
+    },
 }
 /// Builder for a list box widget
 #[derive(Copy, Clone, Debug)]
@@ -21,6 +25,7 @@ enum Size {
 pub struct ListBox<'a> {
     label: &'a ImStr,
     size: Size,
+    focus_selected: Option<usize>,
 }
 
 impl<'a> ListBox<'a> {
@@ -29,8 +34,24 @@ impl<'a> ListBox<'a> {
         ListBox {
             label,
             size: Size::Vec(sys::ImVec2::zero()),
+            focus_selected: None,
         }
     }
+
+    /// Scrolls so the item at `idx` is brought into view the first frame this list box
+    /// becomes visible, so a caller-supplied selection doesn't start out of sight.
+    ///
+    /// Detected via [`sys::igIsWindowAppearing`] on the list box's child window, so manual
+    /// scrolling by the user on later frames is left alone.
+    ///
+    /// Applied by [`Self::build_simple`], [`Self::build_interactive`] and
+    /// [`Self::build_styled`]; [`Self::begin`], [`Self::build`] and
+    /// [`Self::build_multi_select`] do not scroll to it.
+    #[inline]
+    pub const fn focus_selected(mut self, idx: Option<usize>) -> Self {
+        self.focus_selected = idx;
+        self
+    }
     /// Sets the list box size based on the number of items that you want to make visible
     /// Size default to hold ~7.25 items.
     /// We add +25% worth of item height to allow the user to see at a glance if there are more items up/down, without looking at the scrollbar.
@@ -55,6 +76,25 @@ impl<'a> ListBox<'a> {
         self.size = Size::Vec(sys::ImVec2::new(size[0], size[1]));
         self
     }
+
+    /// Sizes the list box to exactly fit its widest item, instead of the usual
+    /// [`CalcItemWidth`][sys::igCalcItemWidth]-derived width. Height is capped like
+    /// [`Self::calculate_size`], showing at most `max_height_in_items` rows at once.
+    ///
+    /// The width is only known after the items have been iterated, so it settles one
+    /// frame late: the box uses the previous frame's measured width while this frame's
+    /// pass recomputes it, which can cause a brief flicker when the widest item changes.
+    ///
+    /// Items are measured only by [`Self::build_simple`], [`Self::build_interactive`] and
+    /// [`Self::build_styled`]; [`Self::begin`], [`Self::build`] and
+    /// [`Self::build_multi_select`] measure nothing and cache no fitted width.
+    #[inline]
+    pub const fn auto_width(mut self, max_height_in_items: i32) -> Self {
+        self.size = Size::AutoWidth {
+            max_height_in_items,
+        };
+        self
+    }
     /// Creates a list box and starts appending to it.
     ///
     /// Returns `Some(ListBoxToken)` if the list box is open. After content has been
@@ -76,7 +116,28 @@ impl<'a> ListBox<'a> {
                         height_in_items
                     } as f32;
                     let style = *sys::igGetStyle();
-                    let height = sys::igGetTextLineHeightWithSpacing() + height_in_items_f + style.FramePadding.y * 2.0;
+                    let height = sys::igGetTextLineHeightWithSpacing() * height_in_items_f + style.FramePadding.y * 2.0;
+                    let size = sys::ImVec2::new(0.0, height);
+                    sys::igBeginListBox(self.label.as_ptr(), size)
+                }
+                Size::AutoWidth {
+                    max_height_in_items,
+                } => {
+                    let cached_width = sys::ImGuiStorage_GetFloat(
+                        sys::igGetStateStorage(),
+                        self.auto_width_storage_id(),
+                        0.0,
+                    );
+                    if cached_width > 0.0 {
+                        sys::igSetNextItemWidth(cached_width);
+                    }
+                    let height_in_items_f = if max_height_in_items < 0 {
+                        7
+                    } else {
+                        max_height_in_items
+                    } as f32;
+                    let style = *sys::igGetStyle();
+                    let height = sys::igGetTextLineHeightWithSpacing() * height_in_items_f + style.FramePadding.y * 2.0;
                     let size = sys::ImVec2::new(0.0, height);
                     sys::igBeginListBox(self.label.as_ptr(), size)
                 }
@@ -97,6 +158,37 @@ impl<'a> ListBox<'a> {
             list.end(ui);
         }
     }
+
+    fn auto_width_storage_id(&self) -> sys::ImGuiID {
+        unsafe { sys::igGetID_Str(self.label.as_ptr()) }
+    }
+
+    /// Folds `text`'s width into `max_width` when this list box is in [`Size::AutoWidth`]
+    /// mode; a no-op for every other sizing mode.
+    fn track_auto_width(&self, text: &ImStr, max_width: &mut f32) {
+        if let Size::AutoWidth { .. } = self.size {
+            let text_size = unsafe { sys::igCalcTextSize(text.as_ptr(), ptr::null(), false, -1.0) };
+            if text_size.x > *max_width {
+                *max_width = text_size.x;
+            }
+        }
+    }
+
+    /// Caches `max_width`, padded for the frame and scrollbar, so the next frame's
+    /// [`Self::begin`] can request it up front. A no-op outside [`Size::AutoWidth`] mode.
+    fn store_auto_width(&self, max_width: f32) {
+        if let Size::AutoWidth { .. } = self.size {
+            let style = unsafe { *sys::igGetStyle() };
+            let padded_width = max_width + style.FramePadding.x * 2.0 + style.ScrollbarSize;
+            unsafe {
+                sys::ImGuiStorage_SetFloat(
+                    sys::igGetStateStorage(),
+                    self.auto_width_storage_id(),
+                    padded_width,
+                );
+            }
+        }
+    }
 }
 
 /// Tracks a list box that must be ended by calling `.end()`
@@ -121,8 +213,59 @@ impl Drop for ListBoxToken {
     }
 }
 
+/// Visual treatment applied to a single item rendered by [`ListBox::build_styled`].
+#[derive(Copy, Clone, Debug, Default)]
+pub struct ItemStyle {
+    /// Optional RGBA override for the item's text color.
+    pub text_color: Option<[f32; 4]>,
+    /// When `true`, the item is rendered but cannot be selected (e.g. a disabled row).
+    pub disabled: bool,
+}
+
+/// Outcome of rendering a [`ListBox::build_interactive`] pass for one frame.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct ListBoxResponse {
+    /// `true` if an item was clicked this frame (its index is then written to
+    /// `current_item`), including a click on the item that was already selected.
+    pub selected_changed: bool,
+    /// The index of the item that was double-clicked this frame, if any.
+    pub activated: Option<usize>,
+}
+
 /// # Convenience functions
 impl<'a> ListBox<'a> {
+    /// Drives the common per-item bookkeeping shared by [`Self::build_interactive`] and
+    /// [`Self::build_styled`]: opening the list box, measuring auto-width candidates,
+    /// scrolling a focused item into view on first open, and caching the measured width
+    /// once items have actually been walked. `render_item` only needs to draw the
+    /// `Selectable` for each item and record whatever it returns.
+    fn build_items<T, L>(
+        self,
+        ui: &Ui,
+        items: &[T],
+        label_fn: &L,
+        mut render_item: impl FnMut(&Ui, usize, &T, &ImStr),
+    ) where
+        for<'b> L: Fn(&'b T) -> Cow<'b, ImStr>,
+    {
+        let mut max_width = 0.0;
+        if let Some(_cb) = self.begin(ui) {
+            let appearing = unsafe { sys::igIsWindowAppearing() };
+            for (idx, item) in items.iter().enumerate() {
+                let text = label_fn(item);
+                self.track_auto_width(&text, &mut max_width);
+                render_item(ui, idx, item, &text);
+                if appearing && self.focus_selected == Some(idx) {
+                    unsafe { sys::igSetScrollHereY(0.5) };
+                }
+            }
+            _cb.end(ui);
+            if !items.is_empty() {
+                self.store_auto_width(max_width);
+            }
+        }
+    }
+
     /// Builds a simple list box for choosing from a slice of values
     pub fn build_simple<T, L>(
         self,
@@ -131,6 +274,97 @@ impl<'a> ListBox<'a> {
         items: &[T],
         label_fn: &L,
     ) -> bool
+    where
+        for<'b> L: Fn(&'b T) -> Cow<'b, ImStr>,
+    {
+        self.build_interactive(ui, current_item, items, label_fn)
+            .selected_changed
+    }
+
+    /// Builds a list box like [`Self::build_simple`], but reports double-click activation
+    /// alongside selection changes, for "open on double-click" flows such as file pickers
+    /// or layer lists.
+    pub fn build_interactive<T, L>(
+        self,
+        ui: &Ui,
+        current_item: &mut usize,
+        items: &[T],
+        label_fn: &L,
+    ) -> ListBoxResponse
+    where
+        for<'b> L: Fn(&'b T) -> Cow<'b, ImStr>,
+    {
+        use crate::widget::selectable::Selectable;
+        let mut response = ListBoxResponse::default();
+        self.build_items(ui, items, label_fn, |ui, idx, _item, text| {
+            let selected = idx == *current_item;
+            if Selectable::new(text).selected(selected).build(ui) {
+                *current_item = idx;
+                response.selected_changed = true;
+            }
+            if unsafe { sys::igIsItemHovered(0) } && unsafe { sys::igIsMouseDoubleClicked(0) } {
+                response.activated = Some(idx);
+            }
+        });
+        response
+    }
+
+    /// Builds a list box like [`Self::build_simple`], but lets the caller style each item
+    /// individually (e.g. greying out disabled entries or highlighting search matches) via
+    /// `style_fn`, which is given the item and its index and returns an [`ItemStyle`].
+    pub fn build_styled<T, L, F>(
+        self,
+        ui: &Ui,
+        current_item: &mut usize,
+        items: &[T],
+        label_fn: &L,
+        style_fn: F,
+    ) -> bool
+    where
+        for<'b> L: Fn(&'b T) -> Cow<'b, ImStr>,
+        F: Fn(&T, usize) -> ItemStyle,
+    {
+        use crate::widget::selectable::Selectable;
+        let mut result = false;
+        self.build_items(ui, items, label_fn, |ui, idx, item, text| {
+            let selected = idx == *current_item;
+            let style = style_fn(item, idx);
+            let pushed_color = style.text_color.map(|color| unsafe {
+                sys::igPushStyleColor_Vec4(sys::ImGuiCol_Text as i32, color.into())
+            });
+            let clicked = Selectable::new(text)
+                .selected(selected)
+                .disabled(style.disabled)
+                .build(ui);
+            if pushed_color.is_some() {
+                unsafe { sys::igPopStyleColor(1) };
+            }
+            if clicked {
+                *current_item = idx;
+                result = true;
+            }
+        });
+        result
+    }
+
+    /// Builds a list box that supports the standard desktop multi-selection gestures on
+    /// top of a slice of values: plain click replaces the selection with the clicked item,
+    /// Ctrl/Cmd-click toggles the clicked item in place, and Shift-click extends the
+    /// selection to cover the contiguous range between `anchor` and the clicked item.
+    ///
+    /// `anchor` should be persisted by the caller across frames alongside `selection`; it
+    /// is updated on every click except a pure Shift-click, where it is left untouched so
+    /// repeated Shift-clicks keep extending from the same starting point.
+    ///
+    /// Returns `true` if `selection` changed this frame.
+    pub fn build_multi_select<T, L>(
+        self,
+        ui: &Ui,
+        selection: &mut BTreeSet<usize>,
+        anchor: &mut Option<usize>,
+        items: &[T],
+        label_fn: &L,
+    ) -> bool
     where
         for<'b> L: Fn(&'b T) -> Cow<'b, ImStr>,
     {
@@ -138,12 +372,33 @@ impl<'a> ListBox<'a> {
         let mut result = false;
         let lb = self;
         if let Some(_cb) = lb.begin(ui) {
+            let io = ui.io();
             for (idx, item) in items.iter().enumerate() {
                 let text = label_fn(item);
-                let selected = idx == *current_item;
+                let selected = selection.contains(&idx);
                 if Selectable::new(&text).selected(selected).build(ui) {
-                    *current_item = idx;
-                    result = true;
+                    let before = selection.clone();
+                    if io.key_shift {
+                        let start = anchor.unwrap_or(idx);
+                        let (lo, hi) = if start <= idx { (start, idx) } else { (idx, start) };
+                        selection.clear();
+                        selection.extend(lo..=hi);
+                        if io.key_ctrl || io.key_super {
+                            *anchor = Some(idx);
+                        }
+                    } else if io.key_ctrl || io.key_super {
+                        if !selection.remove(&idx) {
+                            selection.insert(idx);
+                        }
+                        *anchor = Some(idx);
+                    } else {
+                        selection.clear();
+                        selection.insert(idx);
+                        *anchor = Some(idx);
+                    }
+                    if *selection != before {
+                        result = true;
+                    }
                 }
             }
             _cb.end(ui);